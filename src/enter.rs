@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use futures::StreamExt;
+use nu_cli::{
+    CommandArgs, CommandRegistry, Example, OutputStream, RawCommandArgs, WholeStreamCommand,
+};
+use nu_errors::ShellError;
+use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::{Tag, Tagged};
+
+use serde::Deserialize;
+
+use std::path::PathBuf;
+
+use crate::open::{fetch, get_encoding};
+
+pub struct Enter;
+
+#[derive(Deserialize)]
+pub struct EnterArgs {
+    location: Tagged<PathBuf>,
+    encoding: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Enter {
+    fn name(&self) -> &str {
+        "enter"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "location",
+                SyntaxShape::Path,
+                "the location to create a new shell from",
+            )
+            .named(
+                "encoding",
+                SyntaxShape::String,
+                "encoding to use to open the file, note: big5, euc-jp, euc-kr, gbk, gb18030, \
+                 ibm866, iso-2022-jp, iso-8859-{2-16}, koi8-r, koi8-u, shift_jis, utf-16, \
+                 windows-{874,1250-1258}, mac-cyrillic are all supported",
+                Some('e'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Create a new shell and begin at this path."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        enter(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Enter a path as a new shell",
+                example: "enter ../project",
+                result: None,
+            },
+            Example {
+                description: "Enter a file as a new shell, converting it to a table first",
+                example: "enter users.csv",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn enter(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let raw_args = RawCommandArgs {
+        host: args.host.clone(),
+        ctrl_c: args.ctrl_c.clone(),
+        shell_manager: args.shell_manager.clone(),
+        call_info: args.call_info.clone(),
+    };
+
+    let (
+        EnterArgs {
+            location,
+            encoding,
+        },
+        _,
+    ) = args.process(&registry).await?;
+
+    // Reject a bad `--encoding` label up front, before anything is read:
+    // `fetch` only checks the label after reading the file, and a location
+    // without an extension below never reaches `fetch` at all.
+    if encoding.is_some() {
+        get_encoding(encoding.clone())?;
+    }
+
+    let span = location.tag.span;
+    let tag = location.tag.clone();
+    let path = location.item;
+    let location_string = path.to_string_lossy().to_string();
+
+    let ext = match path.extension() {
+        // No extension means there's nothing to convert -- step into the
+        // location itself as a new shell.
+        None => {
+            return Ok(OutputStream::one(ReturnSuccess::action(
+                CommandAction::EnterShell(location_string),
+            )))
+        }
+        Some(ext) => ext.to_string_lossy().to_string(),
+    };
+
+    let (_, tagged_contents) = fetch(&location_string, span, false, encoding).await?;
+
+    if let Some(converter) = registry.get_command(&format!("from {}", ext)) {
+        // Run the converter ourselves rather than emitting `AutoConvert` --
+        // that action just pipes `open`'s output through the converter as
+        // normal pipeline output, it has no way to know this came from
+        // `enter` and push the result onto the shell ring instead.
+        let new_args = raw_args.with_input(vec![tagged_contents]);
+        let converted = converter.run(new_args, &registry).await?;
+        let value = converted_value(converted, tag).await?;
+        Ok(OutputStream::one(ReturnSuccess::action(
+            CommandAction::EnterValueShell(value),
+        )))
+    } else {
+        Ok(OutputStream::one(ReturnSuccess::action(
+            CommandAction::EnterValueShell(tagged_contents),
+        )))
+    }
+}
+
+// Drains a converter's output stream into the single `Value` it produced
+// (multiple rows are folded into one table), erroring out the same way a
+// failed conversion would anywhere else in the pipeline.
+async fn converted_value(mut stream: OutputStream, tag: Tag) -> Result<Value, ShellError> {
+    let mut values = Vec::new();
+    while let Some(item) = stream.next().await {
+        if let ReturnSuccess::Value(value) = item? {
+            values.push(value);
+        }
+    }
+
+    match values.len() {
+        0 => Err(ShellError::labeled_error(
+            "Could not convert file to enter a new shell",
+            "could not convert",
+            tag,
+        )),
+        1 => Ok(values.remove(0)),
+        _ => Ok(nu_protocol::UntaggedValue::Table(values).into_value(tag)),
+    }
+}
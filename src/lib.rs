@@ -1,6 +1,8 @@
+mod enter;
 mod ls;
 mod open;
 mod random_dice;
+mod save;
 mod sys;
 mod utils;
 
@@ -50,6 +52,8 @@ pub async fn run_nu(line: String) -> String {
                 whole_stream_command(random_dice::SubCommand),
                 whole_stream_command(ls::Ls),
                 whole_stream_command(open::Open),
+                whole_stream_command(enter::Enter),
+                whole_stream_command(save::Save),
                 whole_stream_command(sys::Sys),
             ]);
             match parse_and_eval(&line, &mut ctx).await {
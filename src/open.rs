@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use encoding_rs::{Encoding, UTF_8};
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
 use nu_cli::{CommandArgs, CommandRegistry, Example, OutputStream, WholeStreamCommand};
 use nu_errors::ShellError;
 use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
@@ -12,6 +12,12 @@ use wasm_bindgen::prelude::*;
 #[wasm_bindgen(module = "/www/module.js")]
 extern "C" {
     fn readfile(path: String) -> String;
+
+    // A rejected `fetch()` promise (cross-origin without CORS headers, DNS
+    // failure, offline, ...) is the common case for a remote URL, so this
+    // has to be `catch`-able rather than trapping the whole wasm instance.
+    #[wasm_bindgen(catch)]
+    async fn fetchurl(url: String) -> Result<String, JsValue>;
 }
 
 pub struct Open;
@@ -112,6 +118,12 @@ struct JSBuffer {
     data: Vec<u8>,
 }
 
+#[derive(Deserialize)]
+struct JSUrlResponse {
+    data: Vec<u8>,
+    content_type: String,
+}
+
 // Note that we do not output a Stream in "fetch" since it is only used by "enter" command
 // Which we expect to use a concrete Value a not a Stream
 pub async fn fetch(
@@ -120,6 +132,10 @@ pub async fn fetch(
     raw: bool,
     encoding_choice: Option<Tagged<String>>,
 ) -> Result<(Option<String>, Value), ShellError> {
+    if path.starts_with("http://") || path.starts_with("https://") {
+        return fetch_url(path, span, raw, encoding_choice).await;
+    }
+
     let ext = if raw {
         None
     } else {
@@ -142,32 +158,224 @@ pub async fn fetch(
         )
     })?;
 
-    let res = buffer.data;
+    let v = decode_bytes(buffer.data, &file_tag, encoding_choice)?;
+    Ok((ext, v))
+}
 
-    // If no encoding is provided we try to guess the encoding to read the file with
-    let encoding = if encoding_choice.is_none() {
-        UTF_8
-    } else {
-        get_encoding(encoding_choice.clone())?
+// Fetches a remote `http(s)://` URL through the browser's `fetch` API (via the
+// `fetchurl` JS shim) and picks the `from` converter by the response's MIME
+// type, falling back to the URL's path extension the way a native file does.
+async fn fetch_url(
+    url: &str,
+    span: Span,
+    raw: bool,
+    encoding_choice: Option<Tagged<String>>,
+) -> Result<(Option<String>, Value), ShellError> {
+    let url_tag = Tag {
+        span,
+        anchor: Some(AnchorLocation::Url(url.to_string())),
     };
 
-    // If the user specified an encoding, then do not do BOM sniffing
-    let decoded_res = if encoding_choice.is_some() {
-        let (cow_res, _replacements) = encoding.decode_with_bom_removal(&res);
-        cow_res
+    let contents = fetchurl(url.to_string()).await.map_err(|e| {
+        ShellError::labeled_error(
+            format!("Could not fetch url: {:?}", e),
+            "could not fetch",
+            span,
+        )
+    })?;
+    let response: JSUrlResponse = serde_json::from_str(&contents)
+        .map_err(|e| {
+            ShellError::labeled_error(
+                format!("Could not fetch url: {}", e),
+                "could not fetch",
+                span,
+            )
+        })?;
+
+    let ext = if raw {
+        None
     } else {
-        // Otherwise, use the default UTF-8 encoder with BOM sniffing
-        let (cow_res, _actual_encoding, replacements) = encoding.decode(&res);
-        // If we had to use replacement characters then fallback to binary
-        if replacements {
-            return Ok((ext, UntaggedValue::binary(res).into_value(file_tag)));
-        }
-        cow_res
+        mime_to_ext(&response.content_type).or_else(|| {
+            let url_path = url.split(|c| c == '?' || c == '#').next().unwrap_or(url);
+            std::path::Path::new(url_path)
+                .extension()
+                .map(|name| name.to_string_lossy().to_string())
+        })
     };
-    let v = UntaggedValue::string(decoded_res.to_string()).into_value(file_tag);
+
+    let v = decode_bytes(response.data, &url_tag, encoding_choice)?;
     Ok((ext, v))
 }
 
+// Maps a `Content-Type` header to the `from <ext>` converter it corresponds
+// to, matching the MIME-driven behavior the native `open` gets from surf.
+fn mime_to_ext(content_type: &str) -> Option<String> {
+    let mime = content_type.split(';').next().unwrap_or("").trim();
+    let ext = match mime {
+        "application/json" => "json",
+        "text/csv" => "csv",
+        "application/xml" | "text/xml" => "xml",
+        "text/html" => "html",
+        "application/x-yaml" | "application/yaml" | "text/yaml" => "yaml",
+        _ => return None,
+    };
+    Some(ext.to_string())
+}
+
+// Bounded window nushell's `MaybeTextCodec` decodes in, chosen to be large
+// enough to amortize the per-window overhead while keeping memory bounded on
+// large files.
+const DECODE_WINDOW: usize = 8192;
+
+// The fraction of a buffer that has to be genuinely undecodable before we
+// give up on treating it as text and fall back to binary. A handful of bad
+// bytes in an otherwise-text file shouldn't turn the whole thing into an
+// opaque blob.
+const BINARY_FALLBACK_THRESHOLD: f64 = 0.3;
+
+// Sniffs a leading UTF-8/UTF-16 BOM the same way `encoding_rs::Encoding::decode`
+// always does regardless of which `Encoding` it's called on, returning the
+// encoding it indicates and how many leading bytes belong to it.
+fn sniff_bom(data: &[u8]) -> (Option<&'static Encoding>, usize) {
+    if data.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        (Some(UTF_8), 3)
+    } else if data.starts_with(&[0xFF, 0xFE]) {
+        (Some(UTF_16LE), 2)
+    } else if data.starts_with(&[0xFE, 0xFF]) {
+        (Some(UTF_16BE), 2)
+    } else {
+        (None, 0)
+    }
+}
+
+// Feeds `data` through `decoder` in bounded windows, the same windowing the
+// UTF-8-guess path below uses, so large files stay off the stack
+// rather than being decoded in one shot.
+//
+// `decode_to_string` does not grow `text` itself -- it only reports how many
+// source bytes it consumed before running out of room. Some encodings (CJK
+// ones especially) expand to well over the source byte length once decoded
+// to UTF-8, so a single pass per window isn't enough: on `OutputFull` we
+// have to reserve more room and feed the *unconsumed remainder* of the same
+// window back in, rather than moving on to the next one.
+fn decode_windowed(decoder: &mut encoding_rs::Decoder, data: &[u8], text: &mut String) {
+    let mut offset = 0;
+    loop {
+        let end = (offset + DECODE_WINDOW).min(data.len());
+        let last = end == data.len();
+        let mut src = &data[offset..end];
+
+        loop {
+            let (result, consumed, _had_replacements) = decoder.decode_to_string(src, text, last);
+            src = &src[consumed..];
+            match result {
+                encoding_rs::CoderResult::InputEmpty => break,
+                encoding_rs::CoderResult::OutputFull => {
+                    text.reserve(DECODE_WINDOW);
+                }
+            }
+        }
+
+        if last {
+            break;
+        }
+        offset = end;
+    }
+}
+
+fn decode_bytes(
+    data: Vec<u8>,
+    tag: &Tag,
+    encoding_choice: Option<Tagged<String>>,
+) -> Result<Value, ShellError> {
+    if let Some(encoding_choice) = encoding_choice {
+        // The user picked an explicit encoding, so skip BOM sniffing and
+        // decode through it window by window via encoding_rs's streaming
+        // decoder, using the same window size as the UTF-8 guess path below.
+        let encoding = get_encoding(Some(encoding_choice))?;
+        let mut decoder = encoding.new_decoder_with_bom_removal();
+        let mut text = String::with_capacity(data.len());
+        decode_windowed(&mut decoder, &data, &mut text);
+        return Ok(UntaggedValue::string(text).into_value(tag.clone()));
+    }
+
+    // No encoding was requested, so sniff for a UTF-8/UTF-16 BOM the same way
+    // `Encoding::decode` always does and decode the rest of the buffer
+    // through whichever encoding it indicates. A UTF-16 BOM is decoded
+    // straight through the streaming decoder; a UTF-8 BOM (or no BOM at all)
+    // falls into the windowed UTF-8-guess loop below, which can fall back to
+    // binary for genuinely non-text content.
+    let (bom_encoding, bom_len) = sniff_bom(&data);
+    if let Some(encoding) = bom_encoding {
+        if encoding != UTF_8 {
+            let mut decoder = encoding.new_decoder_with_bom_removal();
+            let mut text = String::with_capacity(data.len());
+            decode_windowed(&mut decoder, &data, &mut text);
+            return Ok(UntaggedValue::string(text).into_value(tag.clone()));
+        }
+    }
+    let body = &data[bom_len..];
+
+    // Walk the buffer in bounded windows, attempting `str::from_utf8` on
+    // each one. On a `Utf8Error`, split at `valid_up_to()` -- an unbounded
+    // error there means the trailing bytes are an incomplete multi-byte
+    // sequence cut off by the window boundary, so we carry them into the
+    // next window instead of treating them as invalid.
+    let total_len = body.len();
+    let mut text = String::with_capacity(total_len);
+    let mut invalid_bytes = 0usize;
+    let mut window: Vec<u8> = Vec::new();
+
+    for chunk in body.chunks(DECODE_WINDOW) {
+        window.extend_from_slice(chunk);
+
+        loop {
+            match std::str::from_utf8(&window) {
+                Ok(valid) => {
+                    text.push_str(valid);
+                    window.clear();
+                    break;
+                }
+                Err(e) => {
+                    let valid_up_to = e.valid_up_to();
+                    text.push_str(
+                        std::str::from_utf8(&window[..valid_up_to])
+                            .expect("validated by valid_up_to"),
+                    );
+
+                    match e.error_len() {
+                        Some(bad_len) => {
+                            // A bounded error is a genuinely invalid byte
+                            // sequence, not just a character cut off by the
+                            // window boundary.
+                            invalid_bytes += bad_len;
+                            text.push('\u{FFFD}');
+                            window = window[valid_up_to + bad_len..].to_vec();
+                        }
+                        None => {
+                            window = window[valid_up_to..].to_vec();
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Anything still sitting in `window` once the buffer is exhausted never
+    // completed a valid sequence.
+    if !window.is_empty() {
+        invalid_bytes += window.len();
+        text.push('\u{FFFD}');
+    }
+
+    if total_len > 0 && (invalid_bytes as f64 / total_len as f64) > BINARY_FALLBACK_THRESHOLD {
+        Ok(UntaggedValue::binary(data).into_value(tag.clone()))
+    } else {
+        Ok(UntaggedValue::string(text).into_value(tag.clone()))
+    }
+}
+
 pub fn get_encoding(opt: Option<Tagged<String>>) -> Result<&'static Encoding, ShellError> {
     match opt {
         None => Ok(UTF_8),
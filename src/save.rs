@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use futures::StreamExt;
+use nu_cli::{CommandArgs, CommandRegistry, Example, OutputStream, RawCommandArgs, WholeStreamCommand};
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::{Tag, Tagged};
+
+use serde::Deserialize;
+
+use std::path::PathBuf;
+
+use wasm_bindgen::prelude::*;
+
+use crate::open::get_encoding;
+
+#[wasm_bindgen(module = "/www/module.js")]
+extern "C" {
+    fn writefile(path: String, data: Vec<u8>);
+}
+
+pub struct Save;
+
+#[derive(Deserialize)]
+pub struct SaveArgs {
+    path: Tagged<PathBuf>,
+    raw: Tagged<bool>,
+    encoding: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Save {
+    fn name(&self) -> &str {
+        "save"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("path", SyntaxShape::Path, "the path to save contents to")
+            .switch(
+                "raw",
+                "treat values as-is rather than converting based on the file extension",
+                Some('r'),
+            )
+            .named(
+                "encoding",
+                SyntaxShape::String,
+                "encoding to save the file with (utf-16 labels are written as utf-8)",
+                Some('e'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Save the contents of the pipeline to a file."#
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        save(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Save a table to foo.json, converting it on the way out",
+            example: "echo [1 2 3] | save foo.json",
+            result: None,
+        }]
+    }
+}
+
+async fn save(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let raw_args = RawCommandArgs {
+        host: args.host.clone(),
+        ctrl_c: args.ctrl_c.clone(),
+        shell_manager: args.shell_manager.clone(),
+        call_info: args.call_info.clone(),
+    };
+
+    let (
+        SaveArgs {
+            path,
+            raw,
+            encoding,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let tag = path.tag.clone();
+    let full_path = path.item;
+
+    let ext = if raw.item {
+        None
+    } else {
+        full_path
+            .extension()
+            .map(|name| name.to_string_lossy().to_string())
+    };
+
+    let contents = match ext.as_ref().and_then(|ext| registry.get_command(&format!("to {}", ext)))
+    {
+        Some(converter) => {
+            let new_args = raw_args.with_input(input);
+            let output = converter.run(new_args, &registry).await?;
+            output_to_string(output, &tag).await?
+        }
+        None => input_to_string(input, &tag).await?,
+    };
+
+    let bytes = match encoding {
+        Some(encoding_choice) => {
+            let encoding = get_encoding(Some(encoding_choice))?;
+            encoding.encode(&contents).0.into_owned()
+        }
+        None => contents.into_bytes(),
+    };
+
+    writefile(full_path.to_string_lossy().to_string(), bytes);
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::nothing().into_value(tag),
+    )))
+}
+
+async fn input_to_string(input: nu_cli::InputStream, tag: &Tag) -> Result<String, ShellError> {
+    let values: Vec<_> = input.collect().await;
+    let strings = values
+        .iter()
+        .map(|value| {
+            value.as_string().map_err(|_| {
+                ShellError::labeled_error(
+                    "Could not convert value to savable data",
+                    "required a string-like value",
+                    tag.clone(),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(strings.join("\n"))
+}
+
+async fn output_to_string(mut stream: OutputStream, tag: &Tag) -> Result<String, ShellError> {
+    let mut result = String::new();
+    while let Some(item) = stream.next().await {
+        if let ReturnSuccess::Value(value) = item? {
+            result.push_str(&value.as_string().map_err(|_| {
+                ShellError::labeled_error(
+                    "Could not convert value to savable data",
+                    "required a string-like value",
+                    tag.clone(),
+                )
+            })?);
+        }
+    }
+    Ok(result)
+}